@@ -0,0 +1,187 @@
+/*
+
+Black Hole algorithm
+
+Single-threaded implementation, proposed by Abdolreza Hatamlou in "Black hole: A new
+heuristic optimization approach for data clustering", Information Sciences, 2013.
+
+A population of candidate solutions ("stars") is initialized randomly within the search
+bounds. The star with the best fitness becomes the "black hole" (BH); every other star is
+pulled towards it by a random fraction of the distance separating them, component-wise. If a
+pulled star ends up better than the current BH, it becomes the new BH.
+
+Stars that get close enough to the BH - closer than the "event horizon" radius R - are
+considered to have been absorbed: they are destroyed and a fresh star is respawned at a
+random position in the search space, keeping the population size constant and preventing
+premature convergence around the BH.
+
+R is proportional to how much brighter the BH is than the population as a whole. The original
+paper expresses that brightness as the (positive) objective value itself; here it is obtained
+by min-max normalizing the population's fitness against its own best/worst value for the
+problem's `Direction` (see `brightness_of`), rather than the `1.0 / f(x)` reciprocal trick the
+Firefly module used to use - that trick assumed a minimization problem with strictly positive fitness,
+and produced nonsensical (negative, infinite or NaN) radii otherwise, exactly the failure mode
+`Problem`/`Direction` (see `problem.rs`) was introduced to rule out.
+
+The algorithm has no tuning coefficients beyond population size and termination condition,
+which makes it attractive compared to Firefly/PSO where the coefficients need careful
+selection.
+
+*/
+
+use std::time::Instant;
+use rand::{Rng, thread_rng};
+
+use crate::ff::distance;
+use crate::ff::probe::Probe;
+use crate::problem::{Direction, Problem};
+use crate::termination::{GenerationLimit, TerminationCondition, TerminationContext};
+
+pub struct BlackHoleAlgorithmCfg {
+    termination_condition: Box<dyn TerminationCondition>,
+    //Decides when the algorithm run should stop
+    population_size: u32,
+    //Population size (number of stars)
+}
+
+impl Default for BlackHoleAlgorithmCfg {
+    fn default() -> Self {
+        BlackHoleAlgorithmCfg {
+            termination_condition: Box::new(GenerationLimit::new(1000)),
+            population_size: 25,
+        }
+    }
+}
+
+pub struct BlackHoleAlgorithm {
+    pub config: BlackHoleAlgorithmCfg,
+    pub problem: Box<dyn Problem>,
+    pub probe: Box<dyn Probe>,
+}
+
+impl BlackHoleAlgorithm {
+    fn new(config: BlackHoleAlgorithmCfg, problem: Box<dyn Problem>, probe: Box<dyn Probe>) -> Self {
+        BlackHoleAlgorithm {
+            config,
+            problem,
+            probe,
+        }
+    }
+
+    pub fn execute(&mut self) {
+        self.probe.on_start();
+
+        let direction = self.problem.direction();
+        let dimensions = self.problem.dimensions();
+        let bounds: Vec<_> = (0..dimensions).map(|dimension| self.problem.bounds(dimension)).collect();
+
+        let mut rng = thread_rng();
+        let mut stars: Vec<Vec<f64>> = Vec::new();
+        for _index in 0..self.config.population_size as usize { //Generate initial population
+            let star: Vec<f64> = bounds.iter().map(|bound| rng.gen_range(bound.clone())).collect();
+            stars.push(star);
+        }
+
+        let mut fitness: Vec<f64> = stars.iter().map(|star| self.problem.evaluate(star)).collect();
+        let mut bh_index = index_of_best(&fitness, direction);
+        let mut currentbest = fitness[bh_index];
+        self.probe.on_new_best(&currentbest);
+
+        let start_time = Instant::now();
+        let mut generation: u32 = 0;
+        loop {
+            let ctx = TerminationContext {
+                generation: generation as usize,
+                best_fitness: currentbest,
+                elapsed: start_time.elapsed(),
+            };
+            if self.config.termination_condition.should_terminate(&ctx) {
+                break;
+            }
+            self.probe.on_iteration_start(&generation);
+
+            for index in 0..stars.len() {
+                if index == bh_index {
+                    continue;
+                }
+                for dimension in 0..dimensions {
+                    stars[index][dimension] += rng.gen_range(0.0..1.0) * (stars[bh_index][dimension] - stars[index][dimension]);
+                    stars[index][dimension] = stars[index][dimension].clamp(*bounds[dimension].start(), *bounds[dimension].end());
+                }
+                fitness[index] = self.problem.evaluate(&stars[index]);
+
+                if direction.is_better(fitness[index], fitness[bh_index]) {
+                    bh_index = index;
+                }
+            }
+
+            let best_value = fitness[bh_index];
+            if direction.is_better(best_value, currentbest) {
+                currentbest = best_value;
+                self.probe.on_new_best(&currentbest);
+            } else {
+                self.probe.on_current_best(&currentbest);
+            }
+
+            // Event-horizon radius: stars that fall within it of the black hole are destroyed
+            // and respawned at a fresh random position.
+            let brightness = brightness_of(&fitness, direction);
+            let brightness_sum: f64 = brightness.iter().sum();
+            let radius = brightness[bh_index] / brightness_sum;
+
+            for index in 0..stars.len() {
+                if index == bh_index {
+                    continue;
+                }
+                if distance(&stars[index], &stars[bh_index]) < radius {
+                    let respawned: Vec<f64> = bounds.iter().map(|bound| rng.gen_range(bound.clone())).collect();
+                    fitness[index] = self.problem.evaluate(&respawned);
+                    stars[index] = respawned;
+                }
+            }
+
+            generation += 1;
+        }
+
+        self.probe.on_end();
+    }
+}
+
+/// Index of the best-performing star in `fitness`, per `direction`.
+fn index_of_best(fitness: &[f64], direction: Direction) -> usize {
+    let mut best_index = 0;
+    for index in 1..fitness.len() {
+        if direction.is_better(fitness[index], fitness[best_index]) {
+            best_index = index;
+        }
+    }
+    best_index
+}
+
+/// Min-max normalizes `fitness` against its own best/worst value (per `direction`) into a
+/// strictly positive `(0, 1]` "brightness", where `1.0` is the best star currently present.
+/// Unlike `1.0 / f(x)`, this stays well-behaved for negative fitness and for `Direction::Maximize`
+/// problems, and only ever needs the population's own range rather than a priori bounds.
+fn brightness_of(fitness: &[f64], direction: Direction) -> Vec<f64> {
+    let mut best = fitness[0];
+    let mut worst = fitness[0];
+    for &value in fitness.iter().skip(1) {
+        if direction.is_better(value, best) {
+            best = value;
+        }
+        if direction.is_better(worst, value) {
+            worst = value;
+        }
+    }
+    let span = (best - worst).abs();
+    if span == 0.0 {
+        return vec![1.0; fitness.len()];
+    }
+    fitness.iter().map(|&value| {
+        let normalized = match direction {
+            Direction::Minimize => (worst - value) / span,
+            Direction::Maximize => (value - worst) / span,
+        };
+        normalized.max(f64::EPSILON)
+    }).collect()
+}
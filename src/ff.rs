@@ -57,23 +57,22 @@ effective solution to semantic syntax problems in computer science.
 */
 
 use std::f64;
+use std::time::Instant;
 use rand::{Rng, thread_rng};
+use rayon::prelude::*;
 
 pub mod probe;
 pub mod auxiliary;
 
 use probe::Probe;
 
+use crate::problem::Problem;
+use crate::termination::{GenerationLimit, TerminationCondition, TerminationContext};
+
 
 pub struct FireflyAlgorithmCfg {
-    dimensions: u8,
-    //Nr of dimensions
-    lower_bound: f64,
-    //Lower search bound
-    upper_bound: f64,
-    //Upper search bound
-    max_generations: u32,
-    //Maximum amount of generations
+    termination_condition: Box<dyn TerminationCondition>,
+    //Decides when the algorithm run should stop
     population_size: u32,
     //Population size
     alfa0: f64,
@@ -84,100 +83,121 @@ pub struct FireflyAlgorithmCfg {
     //Light absorption coefficient
     delta: f64,
     //Randomness decrease modifier, 0<delta<1
+    parallel: bool,
+    //Evaluate brightness of a generation's fireflies across threads via rayon
 }
 
 impl Default for FireflyAlgorithmCfg {
     fn default() -> Self {
         FireflyAlgorithmCfg {
-            dimensions: 2,
-            lower_bound: -5.0,
-            upper_bound: 5.0,
-            max_generations: 1000,
+            termination_condition: Box::new(GenerationLimit::new(1000)),
             population_size: 25,
             alfa0: 1.0,
             beta0: 1.0,
             gamma: 0.01,
             delta: 0.97,
+            parallel: false,
         }
     }
 }
 
 pub struct FireflyAlgorithm {
     pub config: FireflyAlgorithmCfg,
-    pub brightness_function: fn(&Vec<f64>) -> f64,
+    pub problem: Box<dyn Problem>,
     pub probe: Box<dyn Probe>,
 
 }
 
 impl FireflyAlgorithm {
-    fn new(config: FireflyAlgorithmCfg, brightness_function: fn(&Vec<f64>) -> f64, probe: Box<dyn Probe>) -> Self {
+    fn new(config: FireflyAlgorithmCfg, problem: Box<dyn Problem>, probe: Box<dyn Probe>) -> Self {
         FireflyAlgorithm {
             config,
-            brightness_function,
+            problem,
             probe,
         }
     }
 
     pub fn execute(&mut self) {
         self.probe.on_start();
+        let direction = self.problem.direction();
+        let dimensions = self.problem.dimensions();
+        let bounds: Vec<_> = (0..dimensions).map(|dimension| self.problem.bounds(dimension)).collect();
+
         let mut population: Vec<Vec<f64>> = Vec::new();
         for _index in 0..self.config.population_size as usize { //Generate initial population
             let mut temp: Vec<f64> = Vec::new();
-            for _dim in 0..self.config.dimensions {
-                temp.push(thread_rng().gen_range(self.config.lower_bound as f64..self.config.upper_bound as f64));
+            for dimension in 0..dimensions {
+                temp.push(thread_rng().gen_range(bounds[dimension].clone()));
             }
             population.push(temp);
         }
-        let mut brightness: Vec<f64> = Vec::new();
-        let temp = population.clone();
-        for point in temp {
-            brightness.push(1 as f64 / (self.brightness_function)(&point)); //TODO USUŃ TEMP CLONEA
-        }
-        let scale = self.config.upper_bound - self.config.lower_bound;
+        let mut fitness: Vec<f64> = if self.config.parallel {
+            population.par_iter().map(|point| self.problem.evaluate(point)).collect()
+        } else {
+            population.iter().map(|point| self.problem.evaluate(point)).collect()
+        };
         let mut alfa = self.config.alfa0;
         let mut rng = thread_rng();
-        let mut currentbest: f64 = f64::MAX;
-        for generation in 0..self.config.max_generations {
+        let mut currentbest: f64 = direction.worst_value();
+        let start_time = Instant::now();
+        let mut generation: u32 = 0;
+        loop {
+            let ctx = TerminationContext {
+                generation: generation as usize,
+                best_fitness: currentbest,
+                elapsed: start_time.elapsed(),
+            };
+            if self.config.termination_condition.should_terminate(&ctx) {
+                break;
+            }
             if generation % 25 == 0 {
                 self.probe.on_iteration_start(&generation)
             }
+            // Compute every firefly's new position from a snapshot of the previous generation
+            // first, then evaluate fitness once per firefly (Yang's note that the n x n
+            // pseudocode only costs one evaluation per firefly per generation), so the
+            // evaluation pass below can be handed to rayon without positions shifting under it.
+            let population_snapshot = population.clone();
+            let fitness_snapshot = fitness.clone();
             for index in 0 as usize..self.config.population_size as usize {
                 for innerindex in 0 as usize..self.config.population_size as usize {
-                    if brightness[index] < brightness[innerindex] {
-                        let const1 = self.config.beta0 * f64::powf(f64::consts::E, -1 as f64 * self.config.gamma * f64::powi(distance(&population[index], &population[innerindex]), 2));
-                        for dimension in 0 as usize..self.config.dimensions as usize {
-                            population[index][dimension] += const1 * (population[innerindex][dimension] - population[index][dimension]) + self.config.alfa0 * alfa * (rng.gen_range(0.01..0.99)/*TODO DODAJ SETTING*/ - 0.5) * scale;
+                    if direction.is_better(fitness_snapshot[innerindex], fitness_snapshot[index]) {
+                        let const1 = self.config.beta0 * f64::powf(f64::consts::E, -1 as f64 * self.config.gamma * f64::powi(distance(&population_snapshot[index], &population_snapshot[innerindex]), 2));
+                        for dimension in 0..dimensions {
+                            population[index][dimension] += const1 * (population_snapshot[innerindex][dimension] - population_snapshot[index][dimension]) + self.config.alfa0 * alfa * (rng.gen_range(0.01..0.99) - 0.5) * (*bounds[dimension].end() - *bounds[dimension].start());
+                            population[index][dimension] = population[index][dimension].clamp(*bounds[dimension].start(), *bounds[dimension].end());
                         }
-                        brightness[index] = 1 as f64 / (self.brightness_function)(&population[index]);
                     }
                 }
             }
+            fitness = if self.config.parallel {
+                population.par_iter().map(|point| self.problem.evaluate(point)).collect()
+            } else {
+                population.iter().map(|point| self.problem.evaluate(point)).collect()
+            };
             alfa = alfa * self.config.delta;
-            if generation % 25 == 0 { //TODO REFACTOR
-                let mut maxpos = 0;
-                let mut maxbright = 0 as f64;
-                for index in 0 as usize..self.config.population_size as usize {
-                    if brightness[index] == f64::INFINITY {
-                        maxpos = index;
-                        break;
-                    }
-                    if brightness[index] > maxbright {
-                        maxbright = brightness[index];
-                        maxpos = index;
-                    }
-                }
-                if (self.brightness_function)(&population[maxpos]) < currentbest {
-                    self.probe.on_new_best(&(self.brightness_function)(&population[maxpos]));
-                    currentbest = (self.brightness_function)(&population[maxpos]);
-                } else {
-                    self.probe.on_current_best();
+
+            // Track the current best every generation (not just on logged ones) so that
+            // termination conditions querying `currentbest` see up-to-date progress.
+            let mut bestpos = 0;
+            for index in 1 as usize..self.config.population_size as usize {
+                if direction.is_better(fitness[index], fitness[bestpos]) {
+                    bestpos = index;
                 }
-                //println!("Gen: {}, x: {}, y: {}", generation, population[maxpos][0], population[maxpos][1]);
+            }
+            let best_value = fitness[bestpos];
+            let improved = direction.is_better(best_value, currentbest);
+            if improved {
+                currentbest = best_value;
             }
             if generation % 25 == 0 {
-                //self.probe.on_iteration_end(&generation); //TODO CHYBA TEGO NIE POTRZEBUJĘ
-                println!();//TODO PO PROSTU WYPISZĘ NEWLINE USUŃ TO
+                if improved {
+                    self.probe.on_new_best(&currentbest);
+                } else {
+                    self.probe.on_current_best(&currentbest);
+                }
             }
+            generation += 1;
         }
         self.probe.on_end();
     }
@@ -0,0 +1,51 @@
+use std::fs::File;
+
+use serde::Serialize;
+
+use crate::ff::probe::Probe;
+
+#[derive(Serialize)]
+struct Record {
+    generation_number: u32,
+    best_fitness: f64,
+}
+
+pub struct CsvProbe {
+    writer: csv::Writer<File>,
+    generation: u32,
+    current_best: f64,
+}
+
+impl CsvProbe {
+    pub fn new(file: &str) -> CsvProbe {
+        CsvProbe {
+            writer: csv::Writer::from_path(file).expect("Could not create CSV writer"),
+            generation: 0,
+            current_best: f64::default(),
+        }
+    }
+
+    fn write_record(&mut self) {
+        self.writer.serialize(Record { generation_number: self.generation, best_fitness: self.current_best }).expect("Could not serialize record");
+    }
+}
+
+impl Probe for CsvProbe {
+    fn on_start(&mut self) {
+
+    }
+    fn on_iteration_start(&mut self, generation: &u32) {
+        self.generation = *generation;
+    }
+    fn on_new_best(&mut self, best_fitness: &f64) {
+        self.current_best = *best_fitness;
+        self.write_record();
+    }
+    fn on_current_best(&mut self, best_fitness: &f64) {
+        self.current_best = *best_fitness;
+        self.write_record();
+    }
+    fn on_end(&mut self) {
+        self.writer.flush().expect("Could not flush CSV writer");
+    }
+}
@@ -0,0 +1,54 @@
+use std::fs;
+
+use serde::Serialize;
+
+use crate::ff::probe::Probe;
+
+#[derive(Serialize)]
+struct Record {
+    generation_number: u32,
+    best_fitness: f64,
+}
+
+pub struct JsonProbe {
+    file: String,
+    generation: u32,
+    current_best: f64,
+    records: Vec<Record>,
+}
+
+impl JsonProbe {
+    pub fn new(file: &str) -> JsonProbe {
+        JsonProbe {
+            file: file.to_string(),
+            generation: 0,
+            current_best: f64::default(),
+            records: Vec::new(),
+        }
+    }
+
+    fn push_record(&mut self) {
+        self.records.push(Record { generation_number: self.generation, best_fitness: self.current_best });
+    }
+}
+
+impl Probe for JsonProbe {
+    fn on_start(&mut self) {
+
+    }
+    fn on_iteration_start(&mut self, generation: &u32) {
+        self.generation = *generation;
+    }
+    fn on_new_best(&mut self, best_fitness: &f64) {
+        self.current_best = *best_fitness;
+        self.push_record();
+    }
+    fn on_current_best(&mut self, best_fitness: &f64) {
+        self.current_best = *best_fitness;
+        self.push_record();
+    }
+    fn on_end(&mut self) {
+        let serialized = serde_json::to_string_pretty(&self.records).expect("Could not serialize records");
+        fs::write(&self.file, serialized).expect("Could not write JSON file");
+    }
+}
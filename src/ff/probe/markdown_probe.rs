@@ -0,0 +1,64 @@
+use std::fs;
+
+use crate::ff::probe::Probe;
+
+struct Record {
+    generation_number: u32,
+    best_fitness: f64,
+}
+
+pub struct MarkdownProbe {
+    file: String,
+    generation: u32,
+    current_best: f64,
+    records: Vec<Record>,
+}
+
+impl MarkdownProbe {
+    pub fn new(file: &str) -> MarkdownProbe {
+        MarkdownProbe {
+            file: file.to_string(),
+            generation: 0,
+            current_best: f64::default(),
+            records: Vec::new(),
+        }
+    }
+
+    fn push_record(&mut self) {
+        self.records.push(Record { generation_number: self.generation, best_fitness: self.current_best });
+    }
+
+    fn render(&self) -> String {
+        let mut table = String::from("| Generation | Best fitness | Improvement |\n|---:|---:|---:|\n");
+        let mut previous_best: Option<f64> = None;
+        for record in self.records.iter() {
+            let improvement = match previous_best {
+                Some(previous) => format!("{:+.6}", record.best_fitness - previous),
+                None => "-".to_string(),
+            };
+            table.push_str(&format!("| {} | {:.6} | {} |\n", record.generation_number, record.best_fitness, improvement));
+            previous_best = Some(record.best_fitness);
+        }
+        table
+    }
+}
+
+impl Probe for MarkdownProbe {
+    fn on_start(&mut self) {
+
+    }
+    fn on_iteration_start(&mut self, generation: &u32) {
+        self.generation = *generation;
+    }
+    fn on_new_best(&mut self, best_fitness: &f64) {
+        self.current_best = *best_fitness;
+        self.push_record();
+    }
+    fn on_current_best(&mut self, best_fitness: &f64) {
+        self.current_best = *best_fitness;
+        self.push_record();
+    }
+    fn on_end(&mut self) {
+        fs::write(&self.file, self.render()).expect("Could not write Markdown summary");
+    }
+}
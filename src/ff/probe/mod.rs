@@ -0,0 +1,22 @@
+pub mod csv_probe;
+pub mod json_probe;
+pub mod markdown_probe;
+pub mod multi_probe;
+
+/// Observes a Firefly Algorithm or Black Hole run. Hooks are called from
+/// `FireflyAlgorithm::execute` / `BlackHoleAlgorithm::execute` at the cadence each decides
+/// (Firefly: every 25th generation for the per-generation hooks; Black Hole: every generation,
+/// plus one `on_new_best` for the initial population before the first generation).
+pub trait Probe {
+    fn on_start(&mut self);
+    /// `generation` is the index of the generation about to run.
+    fn on_iteration_start(&mut self, generation: &u32);
+    /// Called instead of `on_current_best` when this generation improved on the running best.
+    fn on_new_best(&mut self, best_fitness: &f64);
+    /// Called instead of `on_new_best` when this generation did not improve on the running best.
+    /// Takes the running best explicitly (rather than relying on the probe to remember the last
+    /// value handed to `on_new_best`) because in a Firefly run the best can keep improving across
+    /// the 24 unlogged generations in between - without this, the probe would serialize a stale value.
+    fn on_current_best(&mut self, best_fitness: &f64);
+    fn on_end(&mut self);
+}
@@ -0,0 +1,41 @@
+use crate::ff::probe::Probe;
+
+/// Fans every callback out to a list of probes, so a run can be logged to the console and
+/// serialized to disk at the same time.
+pub struct MultiProbe {
+    probes: Vec<Box<dyn Probe>>,
+}
+
+impl MultiProbe {
+    pub fn new(probes: Vec<Box<dyn Probe>>) -> MultiProbe {
+        MultiProbe { probes }
+    }
+}
+
+impl Probe for MultiProbe {
+    fn on_start(&mut self) {
+        for probe in self.probes.iter_mut() {
+            probe.on_start();
+        }
+    }
+    fn on_iteration_start(&mut self, generation: &u32) {
+        for probe in self.probes.iter_mut() {
+            probe.on_iteration_start(generation);
+        }
+    }
+    fn on_new_best(&mut self, best_fitness: &f64) {
+        for probe in self.probes.iter_mut() {
+            probe.on_new_best(best_fitness);
+        }
+    }
+    fn on_current_best(&mut self, best_fitness: &f64) {
+        for probe in self.probes.iter_mut() {
+            probe.on_current_best(best_fitness);
+        }
+    }
+    fn on_end(&mut self) {
+        for probe in self.probes.iter_mut() {
+            probe.on_end();
+        }
+    }
+}
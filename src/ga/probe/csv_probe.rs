@@ -1,3 +1,5 @@
+use std::fs::File;
+
 use crate::ga::{Probe, Individual};
 
 use serde::{Serialize};
@@ -9,37 +11,46 @@ struct Record{
 }
 
 pub struct CsvProbe {
-  // writer: csv::Writer<File>
+  writer: csv::Writer<File>,
+  generation: usize,
 }
 
 impl CsvProbe {
-  pub fn new(file: String) -> CsvProbe {
+  pub fn new(file: &str) -> CsvProbe {
     CsvProbe {
-
+      writer: csv::Writer::from_path(file).expect("Could not create CSV writer"),
+      generation: 0,
     }
   }
+
+  fn write_record(&mut self, best_fitness: f64) {
+    self.writer.serialize(Record { generation_number: self.generation, best_fitness }).expect("Could not serialize record");
+  }
 }
 
 impl Probe for CsvProbe {
   fn on_start(&mut self) {
-    
+
   }
   fn on_new_best(&mut self, individual: &Individual) {
-    
+
   }
   fn on_mutation(&mut self, before: &Individual, after: &Individual) {
-    
+
   }
-  fn on_new_generation(&mut self) {
-    
+  fn on_new_generation(&mut self, generation: &Vec<Individual>) {
+
   }
   fn on_best_fit_in_generation(&mut self, individual: &Individual) {
-    
+    self.write_record(individual.fitness);
   }
   fn on_iteration_start(&mut self, iteration: usize) {
-    
+    self.generation = iteration;
   }
   fn on_iteration_end(&mut self, iteration: usize) {
-    
+
+  }
+  fn on_end(&mut self) {
+    self.writer.flush().expect("Could not flush CSV writer");
   }
 }
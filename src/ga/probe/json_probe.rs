@@ -0,0 +1,55 @@
+use std::fs;
+
+use crate::ga::{Probe, Individual};
+
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct Record {
+  generation_number: usize,
+  best_fitness: f64,
+}
+
+pub struct JsonProbe {
+  file: String,
+  generation: usize,
+  records: Vec<Record>,
+}
+
+impl JsonProbe {
+  pub fn new(file: &str) -> JsonProbe {
+    JsonProbe {
+      file: file.to_string(),
+      generation: 0,
+      records: Vec::new(),
+    }
+  }
+}
+
+impl Probe for JsonProbe {
+  fn on_start(&mut self) {
+
+  }
+  fn on_new_best(&mut self, individual: &Individual) {
+
+  }
+  fn on_mutation(&mut self, before: &Individual, after: &Individual) {
+
+  }
+  fn on_new_generation(&mut self, generation: &Vec<Individual>) {
+
+  }
+  fn on_best_fit_in_generation(&mut self, individual: &Individual) {
+    self.records.push(Record { generation_number: self.generation, best_fitness: individual.fitness });
+  }
+  fn on_iteration_start(&mut self, iteration: usize) {
+    self.generation = iteration;
+  }
+  fn on_iteration_end(&mut self, iteration: usize) {
+
+  }
+  fn on_end(&mut self) {
+    let serialized = serde_json::to_string_pretty(&self.records).expect("Could not serialize records");
+    fs::write(&self.file, serialized).expect("Could not write JSON file");
+  }
+}
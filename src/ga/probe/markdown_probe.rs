@@ -0,0 +1,65 @@
+use std::fs;
+
+use crate::ga::{Probe, Individual};
+
+struct Record {
+  generation_number: usize,
+  best_fitness: f64,
+}
+
+pub struct MarkdownProbe {
+  file: String,
+  generation: usize,
+  records: Vec<Record>,
+}
+
+impl MarkdownProbe {
+  pub fn new(file: &str) -> MarkdownProbe {
+    MarkdownProbe {
+      file: file.to_string(),
+      generation: 0,
+      records: Vec::new(),
+    }
+  }
+
+  fn render(&self) -> String {
+    let mut table = String::from("| Generation | Best fitness | Improvement |\n|---:|---:|---:|\n");
+    let mut previous_best: Option<f64> = None;
+    for record in self.records.iter() {
+      let improvement = match previous_best {
+        Some(previous) => format!("{:+.6}", record.best_fitness - previous),
+        None => "-".to_string(),
+      };
+      table.push_str(&format!("| {} | {:.6} | {} |\n", record.generation_number, record.best_fitness, improvement));
+      previous_best = Some(record.best_fitness);
+    }
+    table
+  }
+}
+
+impl Probe for MarkdownProbe {
+  fn on_start(&mut self) {
+
+  }
+  fn on_new_best(&mut self, individual: &Individual) {
+
+  }
+  fn on_mutation(&mut self, before: &Individual, after: &Individual) {
+
+  }
+  fn on_new_generation(&mut self, generation: &Vec<Individual>) {
+
+  }
+  fn on_best_fit_in_generation(&mut self, individual: &Individual) {
+    self.records.push(Record { generation_number: self.generation, best_fitness: individual.fitness });
+  }
+  fn on_iteration_start(&mut self, iteration: usize) {
+    self.generation = iteration;
+  }
+  fn on_iteration_end(&mut self, iteration: usize) {
+
+  }
+  fn on_end(&mut self) {
+    fs::write(&self.file, self.render()).expect("Could not write Markdown summary");
+  }
+}
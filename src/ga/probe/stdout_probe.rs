@@ -38,4 +38,8 @@ impl Probe for StdoutProbe {
     // TODO: Take iteration count & maybe some more info here (best so far, etc.)
     println!("End of iteration: {}", iteration);
   }
+
+  fn on_end(&mut self) {
+    println!("Execution of genetic algorithm finished");
+  }
 }
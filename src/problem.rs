@@ -0,0 +1,92 @@
+/*
+
+A `Problem` describes what is being optimized: its dimensionality, the feasible region in each
+dimension, how to evaluate a candidate solution, and whether lower or higher values are better.
+
+This replaces the single scalar `lower_bound`/`upper_bound` previously duplicated across every
+algorithm's config (which only supported symmetric, identical-per-dimension search spaces) and
+the `1.0 / f(x)` reciprocal-fitness trick the Firefly algorithm used to turn a minimization
+problem into a "brightness" to maximize - a trick that produces nonsensical results for
+objective values that cross zero. Algorithms rank candidates via `Direction::is_better` instead.
+
+Firefly, PSO and Black Hole are ported onto this trait. The GA and ACO solvers are not: this
+source tree only carries their probe submodules (`ga::probe::*`, `aco::ants_system_v2::probe::*`)
+- the driver code that actually ranks individuals/ants (and would need to be rewritten against
+`Direction::is_better` instead of its own `1.0/f(x)`-or-`f64::MAX`-sentinel comparisons) isn't
+present here to port. That porting is left for whoever brings those drivers into this tree.
+
+*/
+
+use std::ops::RangeInclusive;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Direction {
+    Minimize,
+    Maximize,
+}
+
+impl Direction {
+    /// True if `candidate` is a strict improvement over `incumbent` in this direction.
+    pub fn is_better(&self, candidate: f64, incumbent: f64) -> bool {
+        match self {
+            Direction::Minimize => candidate < incumbent,
+            Direction::Maximize => candidate > incumbent,
+        }
+    }
+
+    /// A fitness value worse than any real evaluation, used to seed a running best before
+    /// anything has been evaluated yet (replaces ad-hoc `f64::MAX`/`0.0` sentinels).
+    pub fn worst_value(&self) -> f64 {
+        match self {
+            Direction::Minimize => f64::INFINITY,
+            Direction::Maximize => f64::NEG_INFINITY,
+        }
+    }
+}
+
+pub trait Problem: Send + Sync {
+    /// Number of dimensions of the search space.
+    fn dimensions(&self) -> usize;
+    /// Feasible range of the given dimension (0-indexed, < `dimensions()`).
+    fn bounds(&self, dimension: usize) -> RangeInclusive<f64>;
+    /// Evaluates a candidate solution. `position.len()` is always `dimensions()`.
+    fn evaluate(&self, position: &[f64]) -> f64;
+    /// Whether `evaluate` should be minimized or maximized.
+    fn direction(&self) -> Direction;
+}
+
+/// Adapts a plain objective function into a `Problem`, optionally with per-dimension bounds.
+pub struct FunctionProblem {
+    function: fn(&[f64]) -> f64,
+    bounds: Vec<RangeInclusive<f64>>,
+    direction: Direction,
+}
+
+impl FunctionProblem {
+    pub fn new(function: fn(&[f64]) -> f64, bounds: Vec<RangeInclusive<f64>>, direction: Direction) -> Self {
+        FunctionProblem { function, bounds, direction }
+    }
+
+    /// Convenience constructor for the common case of identical bounds in every dimension.
+    pub fn with_uniform_bounds(function: fn(&[f64]) -> f64, dimensions: usize, lower_bound: f64, upper_bound: f64, direction: Direction) -> Self {
+        FunctionProblem::new(function, vec![lower_bound..=upper_bound; dimensions], direction)
+    }
+}
+
+impl Problem for FunctionProblem {
+    fn dimensions(&self) -> usize {
+        self.bounds.len()
+    }
+
+    fn bounds(&self, dimension: usize) -> RangeInclusive<f64> {
+        self.bounds[dimension].clone()
+    }
+
+    fn evaluate(&self, position: &[f64]) -> f64 {
+        (self.function)(position)
+    }
+
+    fn direction(&self) -> Direction {
+        self.direction
+    }
+}
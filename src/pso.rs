@@ -1,4 +1,5 @@
 use std::borrow::Borrow;
+use std::time::Instant;
 use itertools::iterate;
 use num::{NumCast, One};
 
@@ -11,90 +12,108 @@ use crate::pso::probe::console_probe::ConsoleProbe;
 use crate::pso::probe::csv_probe::CsvProbe;
 use crate::pso::probe::json_probe::JsonProbe;
 use crate::pso::probe::multi_probe::MultiProbe;
+use crate::problem::{Direction, FunctionProblem, Problem};
 use crate::pso::probe::probe::Probe;
-use crate::pso::swarm::Swarm;
+use crate::pso::swarm::{PSOVariant, Swarm, Topology};
+use crate::termination::{GenerationLimit, TerminationCondition, TerminationContext};
 
 
 struct PSOAlgorithmCfg {
     /**
     Parameters:
-    dimensions: number of dimension of optimized function's domain
-    lower_bound: lower bound of search area in every dimension of the domain
-    upper_bound: upper bound of search area in every dimension of the domain
     particle_count: number of particles to use in optimization (number of particles will be maintained throughout the algorithm's run)
     inertia_weight: specifies how much particles retain their speed from previous iteration (0 - no speed retention, 1 - no slowdown)
     cognitive_coefficient: specifies how much particles are attracted their own best positions
     social_coefficient: specifies how much particles are attracted to entire swarm's best position
-    function: function to be optimized
-    iterations: number of iterations, the algorithm should run for
+    termination_condition: decides when the algorithm run should stop, queried once per iteration
     log_interval: specifies how often algorithm's progress is logged
     probe: used for displaying results / progress of the algorithm
+    variant: selects the inertia-weight or constriction-factor velocity update formula
+    v_max: if set, clamps every velocity component to [-v_max, v_max] after the update
+    topology: selects the neighborhood whose best position attracts each particle
+    parallel: evaluate each particle's fitness across threads via rayon
 
     Example values:
     inertia_weight: 0.5
     cognitive_coefficient: 1.0
-    social_coefficient: 3.0
+    social_coefficient: 3.1
     **/
-    dimensions: usize,
-    lower_bound: f64,
-    upper_bound: f64,
     particle_count: usize,
     inertia_weight: f64,
     cognitive_coefficient: f64,
     social_coefficient: f64,
-    function: fn(&Vec<f64>) -> f64,
-    iterations: usize,
+    termination_condition: Box<dyn TerminationCondition>,
     log_interval: usize,
-    probe: Box<dyn Probe>
+    probe: Box<dyn Probe>,
+    variant: PSOVariant,
+    v_max: Option<f64>,
+    topology: Topology,
+    parallel: bool,
 }
 
 impl Default for PSOAlgorithmCfg {
     fn default() -> Self {
         PSOAlgorithmCfg {
-            dimensions: 2,
-            lower_bound: -10.0,
-            upper_bound: 10.0,
             particle_count: 30,
             inertia_weight: 0.5,
             cognitive_coefficient: 1.0,
-            social_coefficient: 3.0,
-            function: rosenbrock,
-            iterations: 500,
+            // 1.0 + 3.1 = 4.1 keeps `phi` above the 4.0 floor `constriction_factor` requires,
+            // so `PSOVariant::Constriction` works with the defaults, not just `Inertia`.
+            social_coefficient: 3.1,
+            termination_condition: Box::new(GenerationLimit::new(500)),
             log_interval: 10,
-            probe:Box::new(ConsoleProbe::new())
+            probe:Box::new(ConsoleProbe::new()),
+            variant: PSOVariant::Inertia,
+            v_max: None,
+            topology: Topology::Gbest,
+            parallel: false,
         }
     }
 }
 
 struct PSOAlgorithm {
     config: PSOAlgorithmCfg,
+    problem: Box<dyn Problem>,
     swarm: Swarm
 }
 
 impl PSOAlgorithm {
-    fn new(config: PSOAlgorithmCfg) -> Self {
-        let swarm = Swarm::generate(config.particle_count.clone(), config.dimensions.clone(), config.lower_bound.clone(), config.upper_bound.clone(), config.function.borrow());
+    fn new(config: PSOAlgorithmCfg, problem: Box<dyn Problem>) -> Self {
+        let swarm = Swarm::generate(config.particle_count.clone(), problem.borrow(), config.topology);
         PSOAlgorithm {
             config,
+            problem,
             swarm
         }
     }
 
     fn execute(&mut self) {
         self.config.probe.on_begin(&self.swarm);
-        for iteration in 0..self.config.iterations {
-            self.swarm.update_velocities(&self.config.inertia_weight, &self.config.cognitive_coefficient, &self.config.social_coefficient);
-            self.swarm.update_positions(&self.config.function);
-            self.swarm.update_best_position(&self.config.function);
-            if (iteration + 1) % self.config.log_interval == 0 {
-                self.config.probe.on_new_generation(&self.swarm, iteration + 1);
+        let start_time = Instant::now();
+        let mut iteration: usize = 0;
+        loop {
+            let ctx = TerminationContext {
+                generation: iteration,
+                best_fitness: self.swarm.global_best_fitness(),
+                elapsed: start_time.elapsed(),
+            };
+            if self.config.termination_condition.should_terminate(&ctx) {
+                break;
+            }
+            self.swarm.rebuild_topology(iteration);
+            self.swarm.update_velocities(&self.config.inertia_weight, &self.config.cognitive_coefficient, &self.config.social_coefficient, &self.config.variant, self.config.v_max);
+            self.swarm.update_positions(self.problem.borrow());
+            self.swarm.update_best_position(self.problem.borrow(), self.config.parallel);
+            iteration += 1;
+            if iteration % self.config.log_interval == 0 {
+                self.config.probe.on_new_generation(&self.swarm, iteration);
             }
         }
         self.config.probe.on_end(&self.swarm);
     }
 }
 
-fn rosenbrock(x: &Vec<f64>) -> f64 {
+fn rosenbrock(x: &[f64]) -> f64 {
     let _100: f64 = NumCast::from(100).unwrap();
     let mut value: f64 = f64::default();
     for i in 0..x.len() {
@@ -117,14 +136,14 @@ pub fn pso_demo() {
     let probes : Vec<Box<dyn Probe>> = vec![console_probe, csv_probe, json_probe];
 
     let config = PSOAlgorithmCfg{
-        dimensions: 3,
-        iterations,
+        termination_condition: Box::new(GenerationLimit::new(iterations)),
         log_interval: 50,
         probe: Box::new(MultiProbe::new(probes)),
         ..PSOAlgorithmCfg::default()
     };
+    let problem = Box::new(FunctionProblem::with_uniform_bounds(rosenbrock, 3, -10.0, 10.0, Direction::Minimize));
 
-    let mut algorithm = PSOAlgorithm::new(config);
+    let mut algorithm = PSOAlgorithm::new(config, problem);
 
     algorithm.execute();
 }
\ No newline at end of file
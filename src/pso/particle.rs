@@ -0,0 +1,18 @@
+#[derive(Clone, Debug)]
+pub struct Particle {
+    pub position: Vec<f64>,
+    pub velocity: Vec<f64>,
+    pub best_position: Vec<f64>,
+    pub best_fitness: f64,
+}
+
+impl Particle {
+    pub fn new(position: Vec<f64>, velocity: Vec<f64>, fitness: f64) -> Self {
+        Particle {
+            best_position: position.clone(),
+            best_fitness: fitness,
+            position,
+            velocity,
+        }
+    }
+}
@@ -0,0 +1,247 @@
+use rand::{thread_rng, Rng};
+use rayon::prelude::*;
+
+use crate::problem::{Direction, Problem};
+use crate::pso::particle::Particle;
+
+/// Selects the formula used by `Swarm::update_velocities` to combine inertia, cognitive and
+/// social terms. `nbest` below is the particle's neighborhood best as chosen by `Topology`
+/// (the swarm-wide best under `Topology::Gbest`).
+pub enum PSOVariant {
+    /// The classic inertia-weight form: `v = w*v + c1*r1*(pbest - x) + c2*r2*(nbest - x)`.
+    Inertia,
+    /// Clerc's constriction-factor form: `v = chi*(v + c1*r1*(pbest - x) + c2*r2*(nbest - x))`,
+    /// with `chi` derived from `cognitive_coefficient + social_coefficient` (which must exceed
+    /// 4). Keeps the swarm numerically stable without needing a separate inertia weight.
+    Constriction,
+}
+
+/// Selects the neighborhood whose best personal position attracts a given particle (the
+/// "social" term). `Gbest` pulls every particle towards the swarm-wide best, while the other
+/// variants restrict that pull to a neighborhood, which slows premature convergence on
+/// multimodal objectives. For every topology other than `Gbest`, the particle's own personal
+/// best also competes alongside its neighbors' when picking the attractor.
+#[derive(Clone, Copy)]
+pub enum Topology {
+    /// Every particle is attracted to the swarm-wide best position.
+    Gbest,
+    /// Particles are arranged on a ring (by index); each is attracted to the best personal
+    /// position among the `k` neighbors on either side, wrapping around.
+    Ring { k: usize },
+    /// Particles are arranged on a 2D grid; each is attracted to the best personal position
+    /// among its up/down/left/right neighbors, wrapping around the grid.
+    VonNeumann,
+    /// Each particle is attracted to the best personal position among `k` randomly chosen
+    /// neighbors, re-sampled every `rewire_every` iterations.
+    Random { k: usize, rewire_every: usize },
+}
+
+pub struct Swarm {
+    pub particles: Vec<Particle>,
+    pub global_best_position: Vec<f64>,
+    global_best_fitness: f64,
+    direction: Direction,
+    topology: Topology,
+    neighbor_indices: Vec<Vec<usize>>,
+}
+
+impl Swarm {
+    pub fn generate(particle_count: usize, problem: &dyn Problem, topology: Topology) -> Self {
+        let mut rng = thread_rng();
+        let direction = problem.direction();
+        let dimensions = problem.dimensions();
+        let bounds: Vec<_> = (0..dimensions).map(|dimension| problem.bounds(dimension)).collect();
+        let mut particles = Vec::with_capacity(particle_count);
+        let mut global_best_position: Vec<f64> = Vec::new();
+        let mut global_best_fitness = direction.worst_value();
+
+        for _ in 0..particle_count {
+            let position: Vec<f64> = bounds.iter().map(|bound| rng.gen_range(bound.clone())).collect();
+            let velocity: Vec<f64> = bounds.iter().map(|bound| {
+                let range = *bound.end() - *bound.start();
+                rng.gen_range(-range..range)
+            }).collect();
+            let fitness = problem.evaluate(&position);
+
+            if direction.is_better(fitness, global_best_fitness) {
+                global_best_fitness = fitness;
+                global_best_position = position.clone();
+            }
+
+            particles.push(Particle::new(position, velocity, fitness));
+        }
+
+        let neighbor_indices = neighbor_indices_for(&topology, particle_count, &mut rng);
+
+        Swarm {
+            particles,
+            global_best_position,
+            global_best_fitness,
+            direction,
+            topology,
+            neighbor_indices,
+        }
+    }
+
+    pub fn global_best_fitness(&self) -> f64 {
+        self.global_best_fitness
+    }
+
+    /// Re-samples the neighbor lists for `Topology::Random` every `rewire_every` iterations.
+    /// A no-op for the other topologies, whose neighbor lists are fixed for the run, and for
+    /// `rewire_every == 0` (which would mean "never rewire", not "every iteration" - guarded
+    /// here instead of dividing by zero).
+    pub fn rebuild_topology(&mut self, iteration: usize) {
+        if let Topology::Random { rewire_every, .. } = self.topology {
+            if rewire_every != 0 && iteration % rewire_every == 0 {
+                let mut rng = thread_rng();
+                self.neighbor_indices = neighbor_indices_for(&self.topology, self.particles.len(), &mut rng);
+            }
+        }
+    }
+
+    fn neighborhood_best_position(&self, particle_index: usize) -> &Vec<f64> {
+        match self.topology {
+            Topology::Gbest => &self.global_best_position,
+            _ => {
+                let mut best_index = particle_index;
+                let mut best_fitness = self.particles[particle_index].best_fitness;
+                for &neighbor_index in &self.neighbor_indices[particle_index] {
+                    if self.direction.is_better(self.particles[neighbor_index].best_fitness, best_fitness) {
+                        best_fitness = self.particles[neighbor_index].best_fitness;
+                        best_index = neighbor_index;
+                    }
+                }
+                &self.particles[best_index].best_position
+            }
+        }
+    }
+
+    pub fn update_velocities(&mut self, inertia_weight: &f64, cognitive_coefficient: &f64, social_coefficient: &f64, variant: &PSOVariant, v_max: Option<f64>) {
+        let mut rng = thread_rng();
+        let neighborhood_best_positions: Vec<Vec<f64>> = (0..self.particles.len())
+            .map(|index| self.neighborhood_best_position(index).clone())
+            .collect();
+        let chi = match variant {
+            PSOVariant::Inertia => 1.0,
+            PSOVariant::Constriction => constriction_factor(*cognitive_coefficient, *social_coefficient),
+        };
+
+        for (index, particle) in self.particles.iter_mut().enumerate() {
+            let neighborhood_best_position = &neighborhood_best_positions[index];
+            for dimension in 0..particle.velocity.len() {
+                let r1: f64 = rng.gen_range(0.0..1.0);
+                let r2: f64 = rng.gen_range(0.0..1.0);
+                let cognitive_term = cognitive_coefficient * r1 * (particle.best_position[dimension] - particle.position[dimension]);
+                let social_term = social_coefficient * r2 * (neighborhood_best_position[dimension] - particle.position[dimension]);
+
+                particle.velocity[dimension] = match variant {
+                    PSOVariant::Inertia => inertia_weight * particle.velocity[dimension] + cognitive_term + social_term,
+                    PSOVariant::Constriction => chi * (particle.velocity[dimension] + cognitive_term + social_term),
+                };
+
+                if let Some(v_max) = v_max {
+                    particle.velocity[dimension] = particle.velocity[dimension].clamp(-v_max, v_max);
+                }
+            }
+        }
+    }
+
+    pub fn update_positions(&mut self, problem: &dyn Problem) {
+        for particle in self.particles.iter_mut() {
+            for dimension in 0..particle.position.len() {
+                particle.position[dimension] += particle.velocity[dimension];
+                let bound = problem.bounds(dimension);
+                particle.position[dimension] = particle.position[dimension].clamp(*bound.start(), *bound.end());
+            }
+        }
+    }
+
+    pub fn update_best_position(&mut self, problem: &dyn Problem, parallel: bool) {
+        let fitnesses: Vec<f64> = if parallel {
+            self.particles.par_iter().map(|particle| problem.evaluate(&particle.position)).collect()
+        } else {
+            self.particles.iter().map(|particle| problem.evaluate(&particle.position)).collect()
+        };
+
+        for (particle, fitness) in self.particles.iter_mut().zip(fitnesses) {
+            if self.direction.is_better(fitness, particle.best_fitness) {
+                particle.best_fitness = fitness;
+                particle.best_position = particle.position.clone();
+            }
+
+            if self.direction.is_better(fitness, self.global_best_fitness) {
+                self.global_best_fitness = fitness;
+                self.global_best_position = particle.position.clone();
+            }
+        }
+    }
+}
+
+/// Clerc's constriction factor `chi = 2 / |2 - phi - sqrt(phi^2 - 4*phi)|`, where
+/// `phi = cognitive_coefficient + social_coefficient` and `phi` must exceed 4. Checked in every
+/// build (not just debug) because a `phi <= 4.0` otherwise produces a silent `NaN` chi in
+/// release, poisoning every particle's velocity instead of failing loudly.
+fn constriction_factor(cognitive_coefficient: f64, social_coefficient: f64) -> f64 {
+    let phi = cognitive_coefficient + social_coefficient;
+    assert!(phi > 4.0, "Clerc's constriction factor requires cognitive_coefficient + social_coefficient > 4, got {}", phi);
+    2.0 / f64::abs(2.0 - phi - f64::sqrt(phi * phi - 4.0 * phi))
+}
+
+/// Builds the per-particle neighbor index lists for a given topology. Called once at
+/// `Swarm::generate` time, and again whenever `Topology::Random` is due for a rewire.
+fn neighbor_indices_for(topology: &Topology, particle_count: usize, rng: &mut impl Rng) -> Vec<Vec<usize>> {
+    match *topology {
+        Topology::Gbest => Vec::new(),
+        Topology::Ring { k } => (0..particle_count)
+            .map(|index| {
+                (1..=k)
+                    .flat_map(|offset| {
+                        vec![
+                            (index + offset) % particle_count,
+                            (index + particle_count - offset) % particle_count,
+                        ]
+                    })
+                    .collect()
+            })
+            .collect(),
+        Topology::VonNeumann => {
+            let cols = (particle_count as f64).sqrt().ceil() as usize;
+            let rows = (particle_count + cols - 1) / cols;
+            // `particle_count` is rarely a perfect `rows * cols` rectangle, so the last row can
+            // be shorter than `cols`. `row_len_of` returns each row's real length and `col %
+            // row_len_of(target_row)` wraps the up/down neighbor's column into that row instead
+            // of collapsing it onto the last particle, which would otherwise duplicate one
+            // particle's neighborhood across every column beyond the short row's length.
+            let row_len_of = |row: usize| if row == rows - 1 { particle_count - row * cols } else { cols };
+            (0..particle_count)
+                .map(|index| {
+                    let row = index / cols;
+                    let col = index % cols;
+                    let row_len = row_len_of(row);
+                    let up_row = (row + rows - 1) % rows;
+                    let down_row = (row + 1) % rows;
+
+                    vec![
+                        row * cols + (col + row_len - 1) % row_len,
+                        row * cols + (col + 1) % row_len,
+                        up_row * cols + col % row_len_of(up_row),
+                        down_row * cols + col % row_len_of(down_row),
+                    ]
+                })
+                .collect()
+        }
+        Topology::Random { k, .. } => (0..particle_count)
+            .map(|index| {
+                let mut neighbors = Vec::with_capacity(k);
+                while neighbors.len() < k.min(particle_count.saturating_sub(1)) {
+                    let candidate = rng.gen_range(0..particle_count);
+                    if candidate != index && !neighbors.contains(&candidate) {
+                        neighbors.push(candidate);
+                    }
+                }
+                neighbors
+            })
+            .collect(),
+    }
+}
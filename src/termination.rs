@@ -0,0 +1,163 @@
+/*
+
+Shared termination-condition machinery, used by the Firefly and PSO algorithms (and intended
+for the ACO solvers as they are brought up to the same interface).
+
+Each algorithm queries a `TerminationCondition` once per generation/iteration, handing it a
+`TerminationContext` snapshot of its current progress. This replaces the previous approach of
+looping a hard-coded number of times: callers can now stop as soon as a target fitness is
+reached, a time budget is exhausted, or progress has stagnated, instead of always burning the
+full generation budget.
+
+*/
+
+use std::time::Duration;
+
+use crate::problem::Direction;
+
+/// Snapshot of an algorithm's progress at the moment a `TerminationCondition` is queried.
+pub struct TerminationContext {
+    pub generation: usize,
+    pub best_fitness: f64,
+    pub elapsed: Duration,
+}
+
+pub trait TerminationCondition {
+    fn should_terminate(&mut self, ctx: &TerminationContext) -> bool;
+}
+
+/// Stops once `generation` reaches `limit`. This is the condition implied by the old
+/// `max_generations`/`iterations` fields and is used as the default for the Firefly, PSO and
+/// Black Hole algorithms.
+pub struct GenerationLimit {
+    limit: usize,
+}
+
+impl GenerationLimit {
+    pub fn new(limit: usize) -> Self {
+        GenerationLimit { limit }
+    }
+}
+
+impl TerminationCondition for GenerationLimit {
+    fn should_terminate(&mut self, ctx: &TerminationContext) -> bool {
+        ctx.generation >= self.limit
+    }
+}
+
+/// Stops once `best_fitness` comes within `epsilon` of `value`.
+pub struct TargetReached {
+    value: f64,
+    epsilon: f64,
+}
+
+impl TargetReached {
+    pub fn new(value: f64, epsilon: f64) -> Self {
+        TargetReached { value, epsilon }
+    }
+}
+
+impl TerminationCondition for TargetReached {
+    fn should_terminate(&mut self, ctx: &TerminationContext) -> bool {
+        (ctx.best_fitness - self.value).abs() <= self.epsilon
+    }
+}
+
+/// Stops once the run has been going for at least `limit` wall-clock time.
+pub struct WallClockLimit {
+    limit: Duration,
+}
+
+impl WallClockLimit {
+    pub fn new(limit: Duration) -> Self {
+        WallClockLimit { limit }
+    }
+}
+
+impl TerminationCondition for WallClockLimit {
+    fn should_terminate(&mut self, ctx: &TerminationContext) -> bool {
+        ctx.elapsed >= self.limit
+    }
+}
+
+/// Stops once `best_fitness` has not improved by more than `min_delta` over the last `window`
+/// generations. Tracks a rolling best and a counter that resets on every improvement.
+///
+/// Direction-aware: `direction` decides both the sentinel `best_so_far` is seeded with and
+/// which side of `best_so_far` counts as an improvement, so this works for `Maximize` problems
+/// exactly as it does for `Minimize` ones.
+pub struct Stagnation {
+    window: usize,
+    min_delta: f64,
+    direction: Direction,
+    best_so_far: f64,
+    generations_without_improvement: usize,
+}
+
+impl Stagnation {
+    pub fn new(window: usize, min_delta: f64, direction: Direction) -> Self {
+        Stagnation {
+            window,
+            min_delta,
+            direction,
+            best_so_far: direction.worst_value(),
+            generations_without_improvement: 0,
+        }
+    }
+}
+
+impl TerminationCondition for Stagnation {
+    fn should_terminate(&mut self, ctx: &TerminationContext) -> bool {
+        let margined_fitness = match self.direction {
+            Direction::Minimize => ctx.best_fitness + self.min_delta,
+            Direction::Maximize => ctx.best_fitness - self.min_delta,
+        };
+        if self.direction.is_better(margined_fitness, self.best_so_far) {
+            self.best_so_far = ctx.best_fitness;
+            self.generations_without_improvement = 0;
+        } else {
+            self.generations_without_improvement += 1;
+        }
+        self.generations_without_improvement >= self.window
+    }
+}
+
+/// Combinator: terminates as soon as any of the wrapped conditions does.
+pub struct Any {
+    conditions: Vec<Box<dyn TerminationCondition>>,
+}
+
+impl Any {
+    pub fn new(conditions: Vec<Box<dyn TerminationCondition>>) -> Self {
+        Any { conditions }
+    }
+}
+
+impl TerminationCondition for Any {
+    fn should_terminate(&mut self, ctx: &TerminationContext) -> bool {
+        // Every condition is queried, so stateful conditions (e.g. `Stagnation`) keep tracking
+        // progress even while another condition in the combinator is the one that fires.
+        self.conditions
+            .iter_mut()
+            .fold(false, |terminate, condition| condition.should_terminate(ctx) || terminate)
+    }
+}
+
+/// Combinator: terminates only once every wrapped condition does.
+pub struct All {
+    conditions: Vec<Box<dyn TerminationCondition>>,
+}
+
+impl All {
+    pub fn new(conditions: Vec<Box<dyn TerminationCondition>>) -> Self {
+        All { conditions }
+    }
+}
+
+impl TerminationCondition for All {
+    fn should_terminate(&mut self, ctx: &TerminationContext) -> bool {
+        self.conditions
+            .iter_mut()
+            .fold(true, |terminate, condition| condition.should_terminate(ctx) && terminate)
+    }
+}